@@ -4,7 +4,7 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::fs;
-use std::io::{Read, Write};
+use std::io::{IsTerminal, Read, Write};
 use std::path::PathBuf;
 
 // Name of generator template that should be existing in each starter folder
@@ -20,6 +20,63 @@ pub struct Template {
     pub file_patterns: Option<Vec<Regex>>,
     /// List of rules for placeholder replacement in the generator.
     pub rules: Option<Vec<TemplateRule>>,
+    /// List of user-supplied variables that are collected interactively before generation.
+    pub variables: Option<Vec<TemplateVariable>>,
+    #[serde(default)]
+    /// Rendering engine used for files matched by `file_patterns`. Defaults to the regex-based
+    /// `rules` replacement for backward compatibility.
+    pub engine: TemplateEngine,
+    /// Glob patterns for paths to skip entirely during generation (e.g. `**/*.lock`, `.git/`,
+    /// `node_modules/`), on top of whatever the starter's own `.gitignore`/`.ignore` excludes.
+    pub exclude: Option<Vec<String>>,
+    /// Rules for renaming files and directories whose name contains a placeholder token, e.g.
+    /// `__lib_name__/main.rs` -> `my_app/main.rs`. Evaluated against each path's own file/directory
+    /// name (not its full path), the same way `rules` is evaluated against file content.
+    pub path_rules: Option<Vec<TemplateRule>>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+/// Selects how a [`Template`] turns its source files into generated output.
+pub enum TemplateEngine {
+    #[default]
+    /// Run the configured `rules` as plain regex replacements (the original behavior).
+    Regex,
+    /// Render the whole file through the Tera engine, exposing `lib_name`, `secret`, and every
+    /// resolved `variables` entry as template context, so starters can use `{% if %}`/`{% for %}`.
+    Tera,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+/// The accepted value shape for a [`TemplateVariable`].
+#[serde(rename_all = "lowercase")]
+pub enum VariableType {
+    String,
+    Bool,
+    Integer,
+    Choice,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+/// Describes a single value a starter wants collected from the user before generation.
+pub struct TemplateVariable {
+    /// Name used to reference the resolved value, e.g. `{{name}}` in a rule pattern.
+    pub name: String,
+    /// Text shown to the user when prompting for this variable.
+    pub prompt: String,
+    #[serde(rename = "type")]
+    /// Expected shape of the answer.
+    pub var_type: VariableType,
+    /// Allowed answers when `var_type` is [`VariableType::Choice`].
+    pub options: Option<Vec<String>>,
+    /// Value used when the user leaves the prompt empty, or when stdin is not a TTY.
+    pub default: Option<String>,
+    #[serde(with = "serde_regex", default, skip_serializing)]
+    /// Regex the answer must match, re-prompting until it does.
+    pub validation: Option<Regex>,
+    /// When set, the prompt is only shown if the referenced condition holds, e.g.
+    /// `other_var == "yes"`. Otherwise the variable resolves to its default (or an empty string).
+    pub ask_if: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -27,13 +84,40 @@ pub struct Template {
 pub struct ArgsPlaceholder {
     pub lib_name: String,
     pub secret: String,
+    /// Resolved answers for the starter's `variables`, keyed by variable name.
+    pub variables: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
 /// Enum representing different kinds of template rules.
 pub enum TemplateRuleKind {
     LibName,
+    /// `lib_name` rewritten in `snake_case`, e.g. `my-app` -> `my_app`.
+    LibNameSnake,
+    /// `lib_name` rewritten in `PascalCase`, e.g. `my-app` -> `MyApp`.
+    LibNamePascal,
+    /// `lib_name` rewritten in `camelCase`, e.g. `my-app` -> `myApp`.
+    LibNameCamel,
+    /// `lib_name` rewritten in `kebab-case`, e.g. `my_app` -> `my-app`.
+    LibNameKebab,
+    /// `lib_name` rewritten in `SHOUTY_SNAKE_CASE`, e.g. `my-app` -> `MY_APP`.
+    LibNameShouty,
     Secret,
+    /// Generates a fresh cryptographically-random string of `length` characters (default 64),
+    /// sampled from `charset` (default alphanumeric). A new value is computed per match.
+    GeneratedSecret {
+        length: Option<usize>,
+        charset: Option<String>,
+    },
+    /// Generates a random v4 UUID.
+    Uuid,
+    /// The current time formatted with a `chrono::format::strftime` pattern.
+    Now {
+        format: String,
+    },
+    /// Reads an environment variable, failing if it is unset.
+    Env(String),
+    Var(String),
     Any(String),
 }
 
@@ -48,23 +132,98 @@ impl<'de> Deserialize<'de> for TemplateRuleKind {
         match &value {
             serde_yaml::Value::String(s) => match s.as_str() {
                 "LibName" => Ok(Self::LibName),
+                "LibNameSnake" => Ok(Self::LibNameSnake),
+                "LibNamePascal" => Ok(Self::LibNamePascal),
+                "LibNameCamel" => Ok(Self::LibNameCamel),
+                "LibNameKebab" => Ok(Self::LibNameKebab),
+                "LibNameShouty" => Ok(Self::LibNameShouty),
                 "Secret" => Ok(Self::Secret),
+                "Uuid" => Ok(Self::Uuid),
                 _ => Ok(Self::Any(s.clone())),
             },
+            serde_yaml::Value::Mapping(map) => {
+                if let Some(name) = map.get(serde_yaml::Value::String("Var".to_string())) {
+                    let name = name
+                        .as_str()
+                        .ok_or_else(|| serde::de::Error::custom("Var value must be a string"))?;
+                    Ok(Self::Var(name.to_string()))
+                } else if let Some(name) = map.get(serde_yaml::Value::String("Env".to_string())) {
+                    let name = name
+                        .as_str()
+                        .ok_or_else(|| serde::de::Error::custom("Env value must be a string"))?;
+                    Ok(Self::Env(name.to_string()))
+                } else if let Some(val) = map.get(serde_yaml::Value::String("Now".to_string())) {
+                    let format = val
+                        .get("format")
+                        .and_then(serde_yaml::Value::as_str)
+                        .ok_or_else(|| serde::de::Error::custom("Now requires a `format` field"))?;
+                    Ok(Self::Now {
+                        format: format.to_string(),
+                    })
+                } else if let Some(val) = map.get(serde_yaml::Value::String("Secret".to_string())) {
+                    let length = val
+                        .get("length")
+                        .and_then(serde_yaml::Value::as_u64)
+                        .map(|n| n as usize);
+                    let charset = val
+                        .get("charset")
+                        .and_then(serde_yaml::Value::as_str)
+                        .map(ToString::to_string);
+                    Ok(Self::GeneratedSecret { length, charset })
+                } else {
+                    Err(serde::de::Error::custom("Invalid TemplateRuleKind value"))
+                }
+            }
             _ => Err(serde::de::Error::custom("Invalid TemplateRuleKind value")),
         }
     }
 }
 
+/// Default length used for [`TemplateRuleKind::GeneratedSecret`] when `length` is omitted.
+const DEFAULT_SECRET_LENGTH: usize = 64;
+/// Default charset used for [`TemplateRuleKind::GeneratedSecret`] when `charset` is omitted.
+const DEFAULT_SECRET_CHARSET: &str =
+    "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
 impl TemplateRuleKind {
-    #[must_use]
     /// Get the value from the rule Kind.
-    pub fn get_val(&self, args: &ArgsPlaceholder) -> String {
-        match self {
+    ///
+    /// # Errors
+    /// Returns an error if an [`Self::Env`] rule references a variable that is not set.
+    pub fn get_val(&self, args: &ArgsPlaceholder) -> eyre::Result<String> {
+        use heck::{ToKebabCase, ToLowerCamelCase, ToPascalCase, ToShoutySnakeCase, ToSnakeCase};
+        use rand::Rng;
+
+        let val = match self {
             Self::LibName => args.lib_name.to_string(),
+            Self::LibNameSnake => args.lib_name.to_snake_case(),
+            Self::LibNamePascal => args.lib_name.to_pascal_case(),
+            Self::LibNameCamel => args.lib_name.to_lower_camel_case(),
+            Self::LibNameKebab => args.lib_name.to_kebab_case(),
+            Self::LibNameShouty => args.lib_name.to_shouty_snake_case(),
             Self::Secret => args.secret.to_string(),
+            Self::GeneratedSecret { length, charset } => {
+                let length = length.unwrap_or(DEFAULT_SECRET_LENGTH);
+                let charset = match charset.as_deref() {
+                    Some("") => eyre::bail!("Secret charset must not be empty"),
+                    Some(charset) => charset,
+                    None => DEFAULT_SECRET_CHARSET,
+                };
+                let charset: Vec<char> = charset.chars().collect();
+                let mut rng = rand::thread_rng();
+                (0..length)
+                    .map(|_| charset[rng.gen_range(0..charset.len())])
+                    .collect()
+            }
+            Self::Uuid => uuid::Uuid::new_v4().to_string(),
+            Self::Now { format } => chrono::Local::now().format(format).to_string(),
+            Self::Env(name) => std::env::var(name)
+                .map_err(|_| eyre::eyre!("environment variable `{name}` is not set"))?,
+            Self::Var(name) => args.variables.get(name).cloned().unwrap_or_default(),
             Self::Any(s) => s.to_string(),
-        }
+        };
+
+        Ok(val)
     }
 }
 
@@ -145,6 +304,185 @@ pub fn collect_templates(path: &std::path::PathBuf) -> eyre::Result<BTreeMap<Str
 }
 
 impl Template {
+    /// Prompts the user on the terminal for each declared [`TemplateVariable`] and returns the
+    /// resolved answers keyed by variable name.
+    ///
+    /// Any name already present in `presets` is taken as-is and never prompted for, which lets
+    /// callers pass variables non-interactively (e.g. from CLI flags). When stdin is not a TTY,
+    /// every remaining variable falls back to its `default` instead of blocking on a prompt.
+    ///
+    /// # Errors
+    /// Returns an error if a variable has no default and stdin is not a TTY, since there would be
+    /// no way to resolve it.
+    pub fn prompt_variables(
+        &self,
+        presets: &BTreeMap<String, String>,
+    ) -> eyre::Result<BTreeMap<String, String>> {
+        let mut resolved = presets.clone();
+        let interactive = std::io::stdin().is_terminal();
+
+        for var in self.variables.as_deref().unwrap_or_default() {
+            if resolved.contains_key(&var.name) {
+                continue;
+            }
+
+            if let Some(condition) = &var.ask_if {
+                if !self.eval_condition(condition, &resolved) {
+                    resolved.insert(var.name.clone(), var.default.clone().unwrap_or_default());
+                    continue;
+                }
+            }
+
+            let value = if interactive {
+                Self::prompt_one(var)?
+            } else if let Some(default) = &var.default {
+                default.clone()
+            } else {
+                eyre::bail!(
+                    "variable `{}` has no default and stdin is not a TTY",
+                    var.name
+                );
+            };
+
+            resolved.insert(var.name.clone(), value);
+        }
+
+        Ok(resolved)
+    }
+
+    /// Resolves every declared variable via [`Self::prompt_variables`] and runs [`Self::generate`]
+    /// with the result. This is the entry point callers should use to go from a loaded `Template`
+    /// to generated files on disk; calling `generate` directly with a hand-built `ArgsPlaceholder`
+    /// skips prompting entirely.
+    ///
+    /// # Errors
+    /// Returns an error if a variable could not be resolved (see `prompt_variables`).
+    pub fn prompt_and_generate(
+        &self,
+        from: &PathBuf,
+        lib_name: String,
+        secret: String,
+        presets: &BTreeMap<String, String>,
+    ) -> eyre::Result<()> {
+        let variables = self.prompt_variables(presets)?;
+        let args = ArgsPlaceholder {
+            lib_name,
+            secret,
+            variables,
+        };
+        self.generate(from, &args);
+
+        Ok(())
+    }
+
+    /// Prompts once for a single variable, re-prompting on an empty answer, a type mismatch, or a
+    /// `validation` regex failure.
+    fn prompt_one(var: &TemplateVariable) -> eyre::Result<String> {
+        loop {
+            println!("{}", var.prompt);
+            if let Some(options) = &var.options {
+                for (idx, option) in options.iter().enumerate() {
+                    println!("  {}) {option}", idx + 1);
+                }
+            }
+            if let Some(default) = &var.default {
+                print!("[{default}]: ");
+            } else {
+                print!("> ");
+            }
+            std::io::stdout().flush()?;
+
+            let mut input = String::new();
+            std::io::stdin().read_line(&mut input)?;
+            let input = input.trim();
+
+            let answer = if input.is_empty() {
+                match &var.default {
+                    Some(default) => default.clone(),
+                    None => continue,
+                }
+            } else {
+                input.to_string()
+            };
+
+            // The menu above shows a 1-based index next to each option, so accept that index as
+            // well as the option text itself.
+            let answer = if var.var_type == VariableType::Choice {
+                match (&var.options, answer.parse::<usize>()) {
+                    (Some(options), Ok(idx)) if idx >= 1 && idx <= options.len() => {
+                        options[idx - 1].clone()
+                    }
+                    _ => answer,
+                }
+            } else {
+                answer
+            };
+
+            if !Self::matches_type(&var.var_type, &answer, var.options.as_deref()) {
+                println!("invalid value for `{}`, please try again", var.name);
+                continue;
+            }
+
+            if let Some(validation) = &var.validation {
+                if !validation.is_match(&answer) {
+                    println!("`{answer}` does not match the expected format, please try again");
+                    continue;
+                }
+            }
+
+            return Ok(answer);
+        }
+    }
+
+    /// Checks that an answer matches the shape declared by `var_type`.
+    fn matches_type(var_type: &VariableType, answer: &str, options: Option<&[String]>) -> bool {
+        match var_type {
+            VariableType::String => true,
+            VariableType::Bool => matches!(answer, "true" | "false" | "yes" | "no" | "y" | "n"),
+            VariableType::Integer => answer.parse::<i64>().is_ok(),
+            VariableType::Choice => {
+                options.is_some_and(|options| options.contains(&answer.to_string()))
+            }
+        }
+    }
+
+    /// Evaluates a simple `name == "value"` condition against the variables resolved so far.
+    /// Unknown variables or malformed conditions are treated as `false`.
+    ///
+    /// When `name` refers to a [`VariableType::Bool`] variable, both sides are compared by
+    /// truthiness (the same `"true"`/`"yes"`/`"y"` rule [`Self::tera_value`] uses) rather than
+    /// literal string equality, so `ask_if: x == "yes"`
+    /// matches regardless of which truthy spelling was actually stored for `x`.
+    fn eval_condition(&self, condition: &str, resolved: &BTreeMap<String, String>) -> bool {
+        let Some((name, expected)) = condition.split_once("==") else {
+            return false;
+        };
+        let name = name.trim();
+        let expected = expected.trim().trim_matches('"');
+
+        let Some(value) = resolved.get(name) else {
+            return false;
+        };
+
+        let is_bool = self
+            .variables
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .any(|var| var.name == name && var.var_type == VariableType::Bool);
+
+        if is_bool {
+            Self::is_truthy(value) == Self::is_truthy(expected)
+        } else {
+            value == expected
+        }
+    }
+
+    /// Whether an answer counts as `true` for a [`VariableType::Bool`] variable.
+    fn is_truthy(answer: &str) -> bool {
+        matches!(answer, "true" | "yes" | "y")
+    }
+
     /// Generates files based on the given template by recursively applying template rules to files
     /// within the specified path.
     ///
@@ -155,14 +493,41 @@ impl Template {
     /// during the application, the error is logged, and the walker is instructed to quit processing
     /// further files in the current subtree.
     pub fn generate(&self, from: &PathBuf, args: &ArgsPlaceholder) {
-        let walker = WalkBuilder::new(from).build_parallel();
+        let mut builder = WalkBuilder::new(from);
+        // Let starters declare their own generator-specific ignore file, on top of whatever
+        // `.gitignore`/`.ignore` files the walker already honors by default.
+        builder.add_custom_ignore_filename("generator.ignore");
+
+        if let Some(exclude) = &self.exclude {
+            let mut overrides = ignore::overrides::OverrideBuilder::new(from);
+            for pattern in exclude {
+                if let Err(e) = overrides.add(&format!("!{pattern}")) {
+                    tracing::debug!(error = e.to_string(), pattern, "invalid exclude pattern");
+                }
+            }
+            match overrides.build() {
+                Ok(overrides) => {
+                    builder.overrides(overrides);
+                }
+                Err(e) => {
+                    tracing::debug!(error = e.to_string(), "could not build exclude overrides");
+                }
+            }
+        }
+
+        // Renaming a directory mid-walk would invalidate paths the parallel walker has already
+        // queued up, so renames are only collected here and applied once the walk is done.
+        let renames = std::sync::Mutex::new(Vec::new());
+
+        let walker = builder.build_parallel();
         walker.run(|| {
+            let renames = &renames;
             Box::new(move |result| {
                 if let Ok(entry) = result {
                     let path = entry.path();
+                    let is_outside_target = !path.starts_with(from.join("target"));
 
-                    if !path.starts_with(from.join("target"))
-                        && Self::should_run_file(path, self.file_patterns.as_ref())
+                    if is_outside_target && Self::should_run_file(path, self.file_patterns.as_ref())
                     {
                         if let Err(e) = self.apply_rules(path, args) {
                             tracing::info!(
@@ -173,16 +538,169 @@ impl Template {
                             return WalkState::Quit;
                         }
                     }
+
+                    if is_outside_target {
+                        match self.rename_path(path, args) {
+                            Ok(Some(renamed)) => {
+                                renames.lock().unwrap().push((path.to_path_buf(), renamed));
+                            }
+                            Ok(None) => {}
+                            Err(e) => {
+                                tracing::info!(
+                                    error = e.to_string(),
+                                    path = path.display().to_string(),
+                                    "could not compute renamed path"
+                                );
+                                return WalkState::Quit;
+                            }
+                        }
+                    }
                 }
                 WalkState::Continue
             })
         });
 
+        Self::apply_renames(renames.into_inner().unwrap());
+
         if let Err(err) = fs::remove_file(from.join(GENERATOR_FILE_NAME)) {
             tracing::debug!(error = err.to_string(), "could not delete generator file");
         }
     }
 
+    /// Computes the renamed *file name* for `path` by applying `path_rules` to its last
+    /// component, the same way [`Self::apply_rules`] applies `rules` to file content.
+    ///
+    /// Matching only the file name (rather than the full path) is deliberate: it means a
+    /// directory and the files underneath it each get exactly one rename entry for their own
+    /// name, instead of every descendant also producing a redundant entry because the ancestor's
+    /// placeholder also shows up in its full path. Returns `Ok(None)` when no `path_rules` entry
+    /// matched, leaving the name untouched.
+    fn rename_path(
+        &self,
+        path: &std::path::Path,
+        args: &ArgsPlaceholder,
+    ) -> eyre::Result<Option<PathBuf>> {
+        let Some(path_rules) = &self.path_rules else {
+            return Ok(None);
+        };
+        let Some(file_name) = path.file_name().and_then(std::ffi::OsStr::to_str) else {
+            return Ok(None);
+        };
+
+        let mut renamed = file_name.to_string();
+        let mut is_changed = false;
+        for rule in path_rules {
+            if Self::path_rule_applies(path, rule.file_patterns.as_ref())
+                && rule.pattern.is_match(&renamed)
+            {
+                let value = rule.kind.get_val(args)?;
+                renamed = rule
+                    .pattern
+                    .replace_all(&renamed, value.as_str())
+                    .to_string();
+                is_changed = true;
+            }
+        }
+
+        Ok(is_changed.then(|| path.with_file_name(renamed)))
+    }
+
+    /// Like [`Self::should_run_file`], but for a `path_rules` entry: gates by the same
+    /// `file_patterns` field, matched against the full path, without restricting to files, since
+    /// `path_rules` also renames directories.
+    fn path_rule_applies(path: &std::path::Path, file_patterns: Option<&Vec<Regex>>) -> bool {
+        let Some(patterns) = file_patterns else {
+            return true;
+        };
+        patterns
+            .iter()
+            .any(|pattern| pattern.is_match(&path.display().to_string()))
+    }
+
+    /// Applies a batch of `(original_path, renamed_path)` pairs collected by [`Self::generate`],
+    /// shallowest path first so a directory is renamed before the entries nested under it.
+    ///
+    /// Because an ancestor directory may have already been renamed by an earlier iteration, each
+    /// pending rename's *original* path is first remapped onto the ancestor's *new* location via
+    /// [`Self::remap_renamed_ancestor`] before it is applied. When the computed destination
+    /// already exists, the rename is logged and skipped rather than silently overwriting it (the
+    /// same way an error elsewhere aborts processing for that subtree), and the original path is
+    /// recorded in `skipped` so that anything nested under it is left alone too, instead of a
+    /// descendant's own rule still renaming it in place underneath the untouched ancestor.
+    fn apply_renames(mut renames: Vec<(PathBuf, PathBuf)>) {
+        renames.sort_by_key(|(from, _)| from.components().count());
+
+        let mut applied: Vec<(PathBuf, PathBuf)> = Vec::new();
+        let mut skipped: Vec<PathBuf> = Vec::new();
+        for (from, to) in renames {
+            if skipped.iter().any(|ancestor| from.starts_with(ancestor)) {
+                tracing::info!(
+                    path = from.display().to_string(),
+                    "ancestor rename was skipped, leaving this path under its original name too"
+                );
+                continue;
+            }
+
+            let remapped_from = Self::remap_renamed_ancestor(&from, &applied);
+            let to = match remapped_from.parent() {
+                Some(parent) => parent.join(to.file_name().unwrap_or_default()),
+                None => to,
+            };
+
+            if to.exists() {
+                tracing::info!(
+                    from = remapped_from.display().to_string(),
+                    to = to.display().to_string(),
+                    "rename destination already exists, leaving the original path in place"
+                );
+                skipped.push(from);
+                continue;
+            }
+
+            if let Err(e) = fs::rename(&remapped_from, &to) {
+                tracing::info!(
+                    error = e.to_string(),
+                    from = remapped_from.display().to_string(),
+                    to = to.display().to_string(),
+                    "could not rename path"
+                );
+                continue;
+            }
+
+            applied.push((from, to));
+        }
+    }
+
+    /// Rewrites `path` so that any previously-renamed ancestor directory is reflected in its
+    /// current, on-disk location, choosing the longest (most specific) matching ancestor from
+    /// `applied`.
+    fn remap_renamed_ancestor(path: &std::path::Path, applied: &[(PathBuf, PathBuf)]) -> PathBuf {
+        let mut best: Option<&(PathBuf, PathBuf)> = None;
+        for entry in applied {
+            if path.starts_with(&entry.0) {
+                let is_more_specific = match best {
+                    Some((best_from, _)) => {
+                        entry.0.components().count() > best_from.components().count()
+                    }
+                    None => true,
+                };
+                if is_more_specific {
+                    best = Some(entry);
+                }
+            }
+        }
+
+        match best {
+            Some((original_ancestor, renamed_ancestor)) => {
+                let suffix = path
+                    .strip_prefix(original_ancestor)
+                    .expect("checked by starts_with above");
+                renamed_ancestor.join(suffix)
+            }
+            None => path.to_path_buf(),
+        }
+    }
+
     /// Applies the specified rules to the content of a file, updating the file in-place with the modified content.
     ///
     /// # Description
@@ -195,18 +713,41 @@ impl Template {
         let mut content = String::new();
         fs::File::open(file)?.read_to_string(&mut content)?;
 
-        let mut is_changed = false;
-        for rule in &self.rules.clone().unwrap_or_default() {
-            if Self::should_run_file(file, rule.file_patterns.as_ref())
-                && rule.pattern.is_match(&content)
-            {
-                content = rule
-                    .pattern
-                    .replace_all(&content, rule.kind.get_val(args))
-                    .to_string();
-                is_changed = true;
+        let is_changed = if self.engine == TemplateEngine::Tera {
+            content = self
+                .render_tera(&content, args)
+                .map_err(std::io::Error::other)?;
+            true
+        } else {
+            let mut is_changed = false;
+            for rule in &self.rules.clone().unwrap_or_default() {
+                if Self::should_run_file(file, rule.file_patterns.as_ref())
+                    && rule.pattern.is_match(&content)
+                {
+                    // Evaluated per match, so computed kinds yield a fresh value each time.
+                    let mut error = None;
+                    let replaced = rule
+                        .pattern
+                        .replace_all(&content, |caps: &regex::Captures<'_>| {
+                            let mut expanded = String::new();
+                            match rule.kind.get_val(args) {
+                                Ok(value) => caps.expand(&value, &mut expanded),
+                                Err(e) => {
+                                    error.get_or_insert(e);
+                                }
+                            }
+                            expanded
+                        })
+                        .to_string();
+                    if let Some(e) = error {
+                        return Err(std::io::Error::other(e));
+                    }
+                    content = replaced;
+                    is_changed = true;
+                }
             }
-        }
+            is_changed
+        };
 
         if is_changed {
             let mut modified_file = fs::File::create(file)?;
@@ -216,7 +757,46 @@ impl Template {
         Ok(())
     }
 
-    /// Determines whether the template rules should be applied to the given file path based on a list of regex patterns.
+    /// Renders `content` through Tera, exposing `lib_name`, `secret`, and every resolved
+    /// `variables` entry on [`ArgsPlaceholder`] as template context. Each variable is converted
+    /// to a real `tera::Value` according to its declared `var_type`, so `bool`/`integer`
+    /// variables behave as `{% if %}` expects instead of every non-empty string being truthy.
+    fn render_tera(&self, content: &str, args: &ArgsPlaceholder) -> tera::Result<String> {
+        let mut context = tera::Context::new();
+        context.insert("lib_name", &args.lib_name);
+        context.insert("secret", &args.secret);
+
+        let var_types: BTreeMap<&str, &VariableType> = self
+            .variables
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .map(|var| (var.name.as_str(), &var.var_type))
+            .collect();
+
+        for (name, value) in &args.variables {
+            context.insert(name, &Self::tera_value(var_types.get(name.as_str()), value));
+        }
+
+        tera::Tera::one_off(content, &context, false)
+    }
+
+    /// Converts a raw, string-typed variable answer into the `tera::Value` its declared
+    /// `var_type` implies, so `bool`/`integer` variables are usable in `{% if %}` conditions
+    /// rather than always being truthy as a non-empty string.
+    fn tera_value(var_type: Option<&&VariableType>, raw: &str) -> tera::Value {
+        match var_type {
+            Some(VariableType::Bool) => tera::Value::Bool(Self::is_truthy(raw)),
+            Some(VariableType::Integer) => raw
+                .parse::<i64>()
+                .map_or_else(|_| tera::Value::String(raw.to_string()), Into::into),
+            _ => tera::Value::String(raw.to_string()),
+        }
+    }
+
+    /// Determines whether the template rules should be applied to the given file path based on a
+    /// list of regex patterns. `exclude` is enforced earlier, via the walker's own overrides, so
+    /// by the time a path reaches here it has already survived that filter.
     fn should_run_file(path: &std::path::Path, patterns: Option<&Vec<Regex>>) -> bool {
         let Some(patterns) = patterns else {
             return true;
@@ -231,3 +811,281 @@ impl Template {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args() -> ArgsPlaceholder {
+        ArgsPlaceholder {
+            lib_name: "my-app".to_string(),
+            secret: "supersecret".to_string(),
+            variables: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn tera_context_treats_falsy_bool_variables_as_false() {
+        let template = Template {
+            description: String::new(),
+            file_patterns: None,
+            rules: None,
+            variables: Some(vec![TemplateVariable {
+                name: "use_postgres".to_string(),
+                prompt: String::new(),
+                var_type: VariableType::Bool,
+                options: None,
+                default: None,
+                validation: None,
+                ask_if: None,
+            }]),
+            engine: TemplateEngine::Tera,
+            exclude: None,
+            path_rules: None,
+        };
+        let mut placeholders = args();
+        placeholders
+            .variables
+            .insert("use_postgres".to_string(), "no".to_string());
+
+        let rendered = template
+            .render_tera(
+                "{% if use_postgres %}postgres{% else %}sqlite{% endif %}",
+                &placeholders,
+            )
+            .unwrap();
+
+        assert_eq!(rendered, "sqlite");
+    }
+
+    #[test]
+    fn eval_condition_compares_bool_variables_by_truthiness() {
+        let template = Template {
+            description: String::new(),
+            file_patterns: None,
+            rules: None,
+            variables: Some(vec![TemplateVariable {
+                name: "use_postgres".to_string(),
+                prompt: String::new(),
+                var_type: VariableType::Bool,
+                options: None,
+                default: None,
+                validation: None,
+                ask_if: None,
+            }]),
+            engine: TemplateEngine::Regex,
+            exclude: None,
+            path_rules: None,
+        };
+
+        let mut resolved = BTreeMap::new();
+        // Stored as the short spelling, compared against the long one: still a match, since
+        // both are truthy for a `Bool` variable.
+        resolved.insert("use_postgres".to_string(), "y".to_string());
+
+        assert!(template.eval_condition("use_postgres == \"yes\"", &resolved));
+    }
+
+    #[test]
+    fn lib_name_case_conversion_kinds_transform_a_hyphenated_name() {
+        let mut placeholders = args();
+        placeholders.lib_name = "my-app".to_string();
+
+        assert_eq!(
+            TemplateRuleKind::LibNameSnake
+                .get_val(&placeholders)
+                .unwrap(),
+            "my_app"
+        );
+        assert_eq!(
+            TemplateRuleKind::LibNamePascal
+                .get_val(&placeholders)
+                .unwrap(),
+            "MyApp"
+        );
+        assert_eq!(
+            TemplateRuleKind::LibNameCamel
+                .get_val(&placeholders)
+                .unwrap(),
+            "myApp"
+        );
+        assert_eq!(
+            TemplateRuleKind::LibNameKebab
+                .get_val(&placeholders)
+                .unwrap(),
+            "my-app"
+        );
+        assert_eq!(
+            TemplateRuleKind::LibNameShouty
+                .get_val(&placeholders)
+                .unwrap(),
+            "MY_APP"
+        );
+    }
+
+    #[test]
+    fn generated_secret_with_empty_charset_errors_instead_of_panicking() {
+        let kind = TemplateRuleKind::GeneratedSecret {
+            length: Some(16),
+            charset: Some(String::new()),
+        };
+
+        assert!(kind.get_val(&args()).is_err());
+    }
+
+    #[test]
+    fn rename_path_only_matches_the_final_component() {
+        let template = Template {
+            description: String::new(),
+            file_patterns: None,
+            rules: None,
+            variables: None,
+            engine: TemplateEngine::Regex,
+            exclude: None,
+            path_rules: Some(vec![TemplateRule {
+                pattern: Regex::new("__lib_name__").unwrap(),
+                kind: TemplateRuleKind::LibNameSnake,
+                file_patterns: None,
+            }]),
+        };
+
+        // The parent directory's placeholder does not also trigger a (redundant) rename for
+        // this file, since only `main.rs`, the file's own name, is matched.
+        let file = std::path::Path::new("/starter/__lib_name__/main.rs");
+        assert_eq!(template.rename_path(file, &args()).unwrap(), None);
+
+        let dir = std::path::Path::new("/starter/__lib_name__");
+        assert_eq!(
+            template.rename_path(dir, &args()).unwrap(),
+            Some(PathBuf::from("/starter/my_app"))
+        );
+    }
+
+    #[test]
+    fn rename_path_honors_a_rules_file_patterns() {
+        let template = Template {
+            description: String::new(),
+            file_patterns: None,
+            rules: None,
+            variables: None,
+            engine: TemplateEngine::Regex,
+            exclude: None,
+            path_rules: Some(vec![TemplateRule {
+                pattern: Regex::new("__lib_name__").unwrap(),
+                kind: TemplateRuleKind::LibNameSnake,
+                file_patterns: Some(vec![Regex::new(r"\.rs$").unwrap()]),
+            }]),
+        };
+
+        // Matches the rule's own pattern, but not its `file_patterns` gate, so it's left alone.
+        let toml_file = std::path::Path::new("/starter/__lib_name__.toml");
+        assert_eq!(template.rename_path(toml_file, &args()).unwrap(), None);
+
+        let rs_file = std::path::Path::new("/starter/__lib_name__.rs");
+        assert_eq!(
+            template.rename_path(rs_file, &args()).unwrap(),
+            Some(PathBuf::from("/starter/my_app.rs"))
+        );
+    }
+
+    #[test]
+    fn apply_renames_remaps_nested_entries_onto_a_renamed_ancestor() {
+        let base = std::env::temp_dir().join("loco_cli_generate_rename_test");
+        let _ = fs::remove_dir_all(&base);
+
+        let old_dir = base.join("__lib_name__");
+        let old_nested_dir = old_dir.join("src");
+        fs::create_dir_all(&old_nested_dir).unwrap();
+        let old_file = old_nested_dir.join("__lib_name__.rs");
+        fs::write(&old_file, "fn main() {}").unwrap();
+
+        let new_dir = base.join("my_app");
+        // Both the directory and the file nested two levels below it need renaming, collected
+        // in the order the (unordered, parallel) walker happened to visit them.
+        let renames = vec![
+            (old_file.clone(), old_nested_dir.join("my_app.rs")),
+            (old_dir.clone(), new_dir.clone()),
+        ];
+
+        Template::apply_renames(renames);
+
+        assert!(!old_dir.exists());
+        assert!(new_dir.join("src").join("my_app.rs").exists());
+
+        fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[test]
+    fn apply_renames_skips_a_colliding_destination() {
+        let base = std::env::temp_dir().join("loco_cli_generate_rename_collision_test");
+        let _ = fs::remove_dir_all(&base);
+        fs::create_dir_all(&base).unwrap();
+
+        let from = base.join("__lib_name__.rs");
+        let to = base.join("my_app.rs");
+        fs::write(&from, "old").unwrap();
+        fs::write(&to, "already here").unwrap();
+
+        Template::apply_renames(vec![(from.clone(), to.clone())]);
+
+        // The pre-existing destination is left untouched and the original file is not renamed
+        // into it, rather than one silently clobbering the other.
+        assert_eq!(fs::read_to_string(&to).unwrap(), "already here");
+        assert!(from.exists());
+
+        fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[test]
+    fn apply_renames_skips_descendants_of_a_skipped_ancestor() {
+        let base = std::env::temp_dir().join("loco_cli_generate_rename_nested_collision_test");
+        let _ = fs::remove_dir_all(&base);
+
+        let old_dir = base.join("__lib_name__");
+        let old_nested_dir = old_dir.join("src");
+        fs::create_dir_all(&old_nested_dir).unwrap();
+        let old_file = old_nested_dir.join("__lib_name__.rs");
+        fs::write(&old_file, "fn main() {}").unwrap();
+
+        // A pre-existing `my_app/` collides with the directory's rename target.
+        let new_dir = base.join("my_app");
+        fs::create_dir_all(&new_dir).unwrap();
+
+        let renames = vec![
+            (old_file.clone(), old_nested_dir.join("my_app.rs")),
+            (old_dir.clone(), new_dir.clone()),
+        ];
+
+        Template::apply_renames(renames);
+
+        // The directory rename was skipped due to the collision, so the nested file must stay
+        // under its original name too, instead of ending up renamed inside the still-old-named
+        // directory.
+        assert!(old_file.exists());
+        assert!(!old_nested_dir.join("my_app.rs").exists());
+
+        fs::remove_dir_all(&base).unwrap();
+    }
+
+    fn overrides_for(from: &std::path::Path, exclude: &[&str]) -> ignore::overrides::Override {
+        let mut builder = ignore::overrides::OverrideBuilder::new(from);
+        for pattern in exclude {
+            builder.add(&format!("!{pattern}")).unwrap();
+        }
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn exclude_overrides_prune_matching_paths_only() {
+        let dir = std::env::temp_dir().join("loco_cli_generate_exclude_test");
+        let overrides = overrides_for(&dir, &["**/*.lock", "node_modules/"]);
+
+        assert!(overrides.matched(dir.join("Cargo.lock"), false).is_ignore());
+        assert!(overrides
+            .matched(dir.join("node_modules"), true)
+            .is_ignore());
+        assert!(!overrides
+            .matched(dir.join("src/main.rs"), false)
+            .is_ignore());
+    }
+}